@@ -0,0 +1,14 @@
+//! Edges between symbols that aren't parent/child, e.g. `impl Trait for
+//! Type`.
+
+use crate::symbol::SymbolId;
+
+/// A relationship between two symbols in the same file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edge {
+    /// `type_id` has an `impl trait_name for Type` block.
+    Implements {
+        type_id: SymbolId,
+        trait_name: String,
+    },
+}
@@ -0,0 +1,385 @@
+//! Extracts symbols and references from a single Rust source file.
+
+use std::collections::{HashMap, HashSet};
+
+use proc_macro2::{TokenStream, TokenTree};
+use syn::visit::{self, Visit};
+
+use crate::edge::Edge;
+use crate::imports::ImportTable;
+use crate::reference::{Origin, Reference};
+use crate::symbol::{MacroArm, Symbol, SymbolId, SymbolKind};
+
+/// The result of extracting one source file: its symbols in declaration
+/// order, every reference found while walking the file (annotated with
+/// where each one resolved from), the edges (e.g. `impl Trait for Type`)
+/// between symbols, and the file's `use` imports.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExtractedFile {
+    pub symbols: Vec<Symbol>,
+    pub references: Vec<Reference>,
+    pub edges: Vec<Edge>,
+    pub imports: ImportTable,
+}
+
+/// Everything defined on a type: its own members plus the traits it
+/// implements, so both can be pulled into context in one query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeMembers<'a> {
+    pub members: Vec<&'a Symbol>,
+    pub implements: Vec<&'a str>,
+}
+
+impl ExtractedFile {
+    /// The symbol named `name`, if one was extracted.
+    pub fn symbol_named(&self, name: &str) -> Option<&Symbol> {
+        self.symbols.iter().find(|s| s.name == name)
+    }
+
+    /// Everything defined on the type named `type_name`: its inherent and
+    /// trait-impl methods (via [`Symbol::parent`]) plus the traits it
+    /// implements (via [`Edge::Implements`]).
+    pub fn members_of(&self, type_name: &str) -> Option<TypeMembers<'_>> {
+        let type_symbol = self.symbol_named(type_name)?;
+        let members = self
+            .symbols
+            .iter()
+            .filter(|s| s.parent == Some(type_symbol.id))
+            .collect();
+        let implements = self
+            .edges
+            .iter()
+            .filter_map(|e| {
+                let Edge::Implements { type_id, trait_name } = e;
+                (*type_id == type_symbol.id).then_some(trait_name.as_str())
+            })
+            .collect();
+        Some(TypeMembers { members, implements })
+    }
+}
+
+/// Parse `source` as a Rust file and extract its symbols and references.
+///
+/// # Errors
+/// Returns an error if `source` is not syntactically valid Rust.
+pub fn extract_file(source: &str) -> syn::Result<ExtractedFile> {
+    let file = syn::parse_file(source)?;
+    let imports = ImportTable::from_file(&file);
+    let mut visitor = Visitor {
+        imports,
+        ..Visitor::default()
+    };
+    // Pre-declare top-level struct/enum symbols so that an `impl` block
+    // can resolve its self type and implemented trait even when the
+    // `impl` is written before the type it targets.
+    for item in &file.items {
+        match item {
+            syn::Item::Struct(s) => {
+                visitor.ensure_symbol(s.ident.to_string(), SymbolKind::Struct);
+            }
+            syn::Item::Enum(e) => {
+                visitor.ensure_symbol(e.ident.to_string(), SymbolKind::Enum);
+            }
+            _ => {}
+        }
+    }
+    visitor.visit_file(&file);
+    Ok(ExtractedFile {
+        symbols: visitor.symbols,
+        references: visitor.references,
+        edges: visitor.edges,
+        imports: visitor.imports,
+    })
+}
+
+#[derive(Default)]
+struct Visitor {
+    symbols: Vec<Symbol>,
+    references: Vec<Reference>,
+    edges: Vec<Edge>,
+    // Macros defined so far, by name. `macro_rules!` is textually scoped:
+    // only invocations appearing after the definition can see it.
+    macros_in_scope: HashMap<String, SymbolId>,
+    // The type whose `impl` block we're currently inside, if any and if
+    // its symbol was found. Top-level structs/enums are pre-declared
+    // before the main walk, so this resolves regardless of whether the
+    // `impl` is written before or after the type it targets.
+    current_impl_type: Option<SymbolId>,
+    imports: ImportTable,
+    // Names bound by `let` or function parameters in the function body
+    // currently being walked. These shadow top-level symbols/imports of
+    // the same name, so they must not be resolved as a reference to them.
+    local_bindings: HashSet<String>,
+}
+
+impl Visitor {
+    fn push_symbol(&mut self, name: String, kind: SymbolKind) -> SymbolId {
+        self.push_symbol_with_parent(name, kind, None)
+    }
+
+    fn push_symbol_with_parent(
+        &mut self,
+        name: String,
+        kind: SymbolKind,
+        parent: Option<SymbolId>,
+    ) -> SymbolId {
+        let id = self.symbols.len();
+        self.symbols.push(Symbol { id, name, kind, parent });
+        id
+    }
+
+    /// Return the id of the existing symbol named `name` with the same
+    /// kind, or push a new one. Used for pre-declaring top-level types so
+    /// they are only ever registered once.
+    fn ensure_symbol(&mut self, name: String, kind: SymbolKind) -> SymbolId {
+        match self
+            .symbols
+            .iter()
+            .find(|s| s.name == name && std::mem::discriminant(&s.kind) == std::mem::discriminant(&kind))
+        {
+            Some(existing) => existing.id,
+            None => self.push_symbol(name, kind),
+        }
+    }
+
+    fn define_macro(&mut self, ident: &syn::Ident, mac: &syn::Macro) {
+        let arms = parse_macro_arms(&mac.tokens);
+        let id = self.push_symbol(ident.to_string(), SymbolKind::Macro { arms });
+        self.macros_in_scope.insert(ident.to_string(), id);
+    }
+
+    fn record_invocation(&mut self, mac: &syn::Macro) {
+        let Some(name) = mac.path.get_ident().map(ToString::to_string) else {
+            return;
+        };
+        let resolved = self.macros_in_scope.get(&name).copied();
+        let origin = resolved.map(Origin::Local);
+        self.references.push(Reference {
+            name,
+            resolved,
+            origin,
+        });
+    }
+
+    /// Resolve the first segment of `path` (e.g. `HashMap` in
+    /// `HashMap::new`) against the symbols defined in this file and the
+    /// file's imports, recording a [`Reference`] when it resolves to
+    /// either. Paths that resolve to neither (built-ins, `Self`, generic
+    /// parameters, ...) are not recorded.
+    fn record_name_reference(&mut self, path: &syn::Path) {
+        let Some(name) = path.segments.first().map(|s| s.ident.to_string()) else {
+            return;
+        };
+        if self.local_bindings.contains(&name) {
+            return;
+        }
+        if let Some(symbol) = self.symbols.iter().find(|s| s.name == name) {
+            self.references.push(Reference {
+                name,
+                resolved: Some(symbol.id),
+                origin: Some(Origin::Local(symbol.id)),
+            });
+        } else if let Some(path) = self.imports.resolve(&name) {
+            let origin = Origin::External(path.to_string());
+            self.references.push(Reference {
+                name,
+                resolved: None,
+                origin: Some(origin),
+            });
+        }
+    }
+}
+
+/// The name of a type written as a plain path, e.g. `MyStruct` from
+/// `Self { .. }`'s `self_ty`. Returns `None` for anything more complex
+/// (references, generics, tuples, ...).
+fn type_path_name(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(p) => p.path.get_ident().map(ToString::to_string),
+        _ => None,
+    }
+}
+
+/// The last segment of a trait path, e.g. `MyTrait` from `some::MyTrait`.
+fn trait_path_name(path: &syn::Path) -> String {
+    path.segments
+        .last()
+        .map(|s| s.ident.to_string())
+        .unwrap_or_default()
+}
+
+/// The names bound by a plain identifier pattern, e.g. `val` from a
+/// function parameter or `let` binding `val: i32`. Only simple bindings
+/// are handled; destructuring patterns are left unbound, which only
+/// means a reference to one of their fields' names won't be shadowed.
+fn pat_ident_name(pat: &syn::Pat) -> Option<String> {
+    match pat {
+        syn::Pat::Ident(i) => Some(i.ident.to_string()),
+        _ => None,
+    }
+}
+
+/// The names of a function's parameters, for seeding its local scope.
+fn param_names(sig: &syn::Signature) -> HashSet<String> {
+    sig.inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::FnArg::Typed(pat_type) => pat_ident_name(&pat_type.pat),
+            syn::FnArg::Receiver(_) => None,
+        })
+        .collect()
+}
+
+impl<'ast> Visit<'ast> for Visitor {
+    fn visit_item_struct(&mut self, node: &'ast syn::ItemStruct) {
+        // Already pre-declared for top-level structs; `ensure_symbol`
+        // only pushes a new symbol for ones nested elsewhere (e.g. inside
+        // a function body).
+        self.ensure_symbol(node.ident.to_string(), SymbolKind::Struct);
+        visit::visit_item_struct(self, node);
+    }
+
+    fn visit_item_enum(&mut self, node: &'ast syn::ItemEnum) {
+        self.ensure_symbol(node.ident.to_string(), SymbolKind::Enum);
+        visit::visit_item_enum(self, node);
+    }
+
+    fn visit_item_trait(&mut self, node: &'ast syn::ItemTrait) {
+        self.push_symbol(node.ident.to_string(), SymbolKind::Trait);
+        visit::visit_item_trait(self, node);
+    }
+
+    fn visit_item_fn(&mut self, node: &'ast syn::ItemFn) {
+        self.push_symbol(node.sig.ident.to_string(), SymbolKind::Fn);
+        let outer = std::mem::replace(&mut self.local_bindings, param_names(&node.sig));
+        visit::visit_item_fn(self, node);
+        self.local_bindings = outer;
+    }
+
+    fn visit_item_const(&mut self, node: &'ast syn::ItemConst) {
+        self.push_symbol(node.ident.to_string(), SymbolKind::Const);
+        visit::visit_item_const(self, node);
+    }
+
+    fn visit_item_static(&mut self, node: &'ast syn::ItemStatic) {
+        self.push_symbol(node.ident.to_string(), SymbolKind::Static);
+        visit::visit_item_static(self, node);
+    }
+
+    fn visit_item_macro(&mut self, node: &'ast syn::ItemMacro) {
+        if node.mac.path.is_ident("macro_rules") {
+            if let Some(ident) = &node.ident {
+                self.define_macro(ident, &node.mac);
+            }
+            // The matcher/body tokens are macro syntax, not real
+            // expressions, so there is nothing further to visit.
+        } else {
+            self.record_invocation(&node.mac);
+        }
+    }
+
+    fn visit_macro(&mut self, node: &'ast syn::Macro) {
+        self.record_invocation(node);
+        visit::visit_macro(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast syn::ItemImpl) {
+        let self_type_id = type_path_name(&node.self_ty).and_then(|name| {
+            self.symbols
+                .iter()
+                .find(|s| s.name == name && matches!(s.kind, SymbolKind::Struct | SymbolKind::Enum))
+                .map(|s| s.id)
+        });
+
+        if let (Some(type_id), Some((_, trait_path, _))) = (self_type_id, &node.trait_) {
+            self.edges.push(Edge::Implements {
+                type_id,
+                trait_name: trait_path_name(trait_path),
+            });
+        }
+
+        let outer = self.current_impl_type;
+        self.current_impl_type = self_type_id;
+        visit::visit_item_impl(self, node);
+        self.current_impl_type = outer;
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast syn::ImplItemFn) {
+        self.push_symbol_with_parent(
+            node.sig.ident.to_string(),
+            SymbolKind::Fn,
+            self.current_impl_type,
+        );
+        let outer = std::mem::replace(&mut self.local_bindings, param_names(&node.sig));
+        visit::visit_impl_item_fn(self, node);
+        self.local_bindings = outer;
+    }
+
+    fn visit_local(&mut self, node: &'ast syn::Local) {
+        // Visit the initializer first: it's evaluated in the outer scope,
+        // before the new binding exists (`let x = x;` refers to the old
+        // `x`, not itself).
+        visit::visit_local(self, node);
+        if let Some(name) = pat_ident_name(&node.pat) {
+            self.local_bindings.insert(name);
+        }
+    }
+
+    fn visit_type_path(&mut self, node: &'ast syn::TypePath) {
+        self.record_name_reference(&node.path);
+        visit::visit_type_path(self, node);
+    }
+
+    fn visit_expr_path(&mut self, node: &'ast syn::ExprPath) {
+        self.record_name_reference(&node.path);
+        visit::visit_expr_path(self, node);
+    }
+}
+
+/// Split a `macro_rules!` body into its arms, capturing each arm's matcher
+/// text and the fragment specifiers it binds.
+fn parse_macro_arms(tokens: &TokenStream) -> Vec<MacroArm> {
+    let mut arms = Vec::new();
+    let mut iter = tokens.clone().into_iter().peekable();
+    while let Some(TokenTree::Group(matcher)) = iter.next() {
+        // `=>`
+        iter.next();
+        iter.next();
+        // Arm body; we only need to consume it here.
+        iter.next();
+        // Optional trailing `;` between arms.
+        if matches!(iter.peek(), Some(TokenTree::Punct(p)) if p.as_char() == ';') {
+            iter.next();
+        }
+
+        let mut fragment_specifiers = Vec::new();
+        collect_fragment_specifiers(matcher.stream(), &mut fragment_specifiers);
+        arms.push(MacroArm {
+            matcher: matcher.stream().to_string(),
+            fragment_specifiers,
+        });
+    }
+    arms
+}
+
+/// Recursively scan a matcher's tokens for `$name:fragment` bindings.
+fn collect_fragment_specifiers(tokens: TokenStream, out: &mut Vec<String>) {
+    let mut iter = tokens.into_iter().peekable();
+    while let Some(tree) = iter.next() {
+        match tree {
+            TokenTree::Punct(p) if p.as_char() == '$' => {
+                if matches!(iter.peek(), Some(TokenTree::Ident(_))) {
+                    iter.next(); // the bound name
+                    if matches!(iter.peek(), Some(TokenTree::Punct(p)) if p.as_char() == ':') {
+                        iter.next();
+                        if let Some(TokenTree::Ident(frag)) = iter.next() {
+                            out.push(frag.to_string());
+                        }
+                    }
+                }
+            }
+            TokenTree::Group(group) => collect_fragment_specifiers(group.stream(), out),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Resolves `use` declarations (including grouped and glob imports) into a
+//! table of local bindings, so a reference to a name can be traced back to
+//! where it came from.
+
+use syn::UseTree;
+
+/// A single local binding introduced by a `use` declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Import {
+    /// The name this import binds in the current file, e.g. `HashMap`, or
+    /// the alias for a `use foo::Bar as Baz;` rename.
+    pub local_name: String,
+    /// The fully qualified path the binding refers to, e.g.
+    /// `"std::collections::HashMap"`.
+    pub path: String,
+}
+
+/// All imports in a file: explicit bindings plus the prefixes brought in
+/// wildcard-style by glob imports.
+///
+/// Glob imports (`use std::fmt::*;`) can't be resolved to a specific path
+/// without knowing every item `std::fmt` exports, so they're kept
+/// separately as prefixes rather than turned into [`Import`]s.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportTable {
+    pub imports: Vec<Import>,
+    pub globs: Vec<String>,
+}
+
+impl ImportTable {
+    /// Build the import table for a parsed file.
+    pub fn from_file(file: &syn::File) -> Self {
+        let mut table = Self::default();
+        for item in &file.items {
+            if let syn::Item::Use(item_use) = item {
+                table.collect(&item_use.tree, "");
+            }
+        }
+        table
+    }
+
+    /// The fully qualified path `name` is bound to, if any `use` in this
+    /// file introduces it as a local binding.
+    pub fn resolve(&self, name: &str) -> Option<&str> {
+        self.imports
+            .iter()
+            .find(|i| i.local_name == name)
+            .map(|i| i.path.as_str())
+    }
+
+    fn collect(&mut self, tree: &UseTree, prefix: &str) {
+        match tree {
+            UseTree::Path(p) => {
+                let next_prefix = if prefix.is_empty() {
+                    p.ident.to_string()
+                } else {
+                    format!("{prefix}::{}", p.ident)
+                };
+                self.collect(&p.tree, &next_prefix);
+            }
+            UseTree::Name(n) => self.imports.push(Import {
+                local_name: n.ident.to_string(),
+                path: format!("{prefix}::{}", n.ident),
+            }),
+            UseTree::Rename(r) => self.imports.push(Import {
+                local_name: r.rename.to_string(),
+                path: format!("{prefix}::{}", r.ident),
+            }),
+            UseTree::Glob(_) => self.globs.push(prefix.to_string()),
+            UseTree::Group(g) => {
+                for item in &g.items {
+                    self.collect(item, prefix);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Symbol extraction for source-aware context assembly.
+//!
+//! [`extractor::extract_file`] parses a single Rust source file and returns
+//! its [`symbol::Symbol`]s plus every [`reference::Reference`] found while
+//! walking it, so downstream context assembly can pull in a definition
+//! (e.g. a macro body) even when a chunk only shows its use site.
+
+pub mod edge;
+pub mod extractor;
+pub mod imports;
+pub mod reference;
+pub mod symbol;
+
+pub use edge::Edge;
+pub use extractor::{extract_file, ExtractedFile, TypeMembers};
+pub use imports::{Import, ImportTable};
+pub use reference::{Origin, Reference};
+pub use symbol::{MacroArm, Symbol, SymbolId, SymbolKind};
@@ -0,0 +1,27 @@
+//! References from a use site (e.g. a macro invocation or a type name)
+//! back to the symbol or import that defines it.
+
+use crate::symbol::SymbolId;
+
+/// Where a resolved [`Reference`] comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Origin {
+    /// Defined by a symbol in the same file.
+    Local(SymbolId),
+    /// Brought in by a `use`, naming the fully qualified path, e.g.
+    /// `"std::collections::HashMap"`.
+    External(String),
+}
+
+/// A name used at some point in the file, along with what it resolved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reference {
+    /// The name as written at the use site, e.g. `"my_macro"`.
+    pub name: String,
+    /// The symbol this reference resolves to, if it is defined in the same
+    /// file and in scope at the use site.
+    pub resolved: Option<SymbolId>,
+    /// Where the reference resolved from: a local symbol or an imported
+    /// path. `None` if it resolved to neither.
+    pub origin: Option<Origin>,
+}
@@ -0,0 +1,38 @@
+//! The symbol data model produced by the Rust extractor.
+
+/// Index of a [`Symbol`] within an [`crate::extractor::ExtractedFile`].
+pub type SymbolId = usize;
+
+/// One matcher arm of a `macro_rules!` definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MacroArm {
+    /// Textual rendering of the arm's matcher, e.g. `"($val : expr)"`.
+    pub matcher: String,
+    /// Fragment specifiers bound by the matcher, in order, e.g. `["expr"]`.
+    pub fragment_specifiers: Vec<String>,
+}
+
+/// The kind of item a [`Symbol`] represents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SymbolKind {
+    Struct,
+    Enum,
+    Trait,
+    Fn,
+    Const,
+    Static,
+    /// A `macro_rules!` definition, with its matcher arms captured so a
+    /// call site that only shows `my_macro!()` can pull in the full body.
+    Macro { arms: Vec<MacroArm> },
+}
+
+/// A named item extracted from a source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub id: SymbolId,
+    pub name: String,
+    pub kind: SymbolKind,
+    /// The container this symbol is defined on, e.g. the struct an `impl`
+    /// method belongs to. `None` for top-level items.
+    pub parent: Option<SymbolId>,
+}
@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::*;
 
 const CONST_VAL: i32 = 10;
 static STATIC_VAL: i32 = 20;
@@ -20,14 +22,36 @@ impl MyStruct {
     fn new() -> Self {
         Self { field: 0 }
     }
+
+    fn field(&self) -> i32 {
+        self.field
+    }
 }
 
 impl MyTrait for MyStruct {
     fn trait_method(&self) {}
 }
 
+impl Default for MyStruct {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 fn my_func() {}
 
+fn build_map() -> HashMap<i32, i32> {
+    HashMap::new()
+}
+
 macro_rules! my_macro {
     () => {};
+    ($val:expr) => {
+        $val
+    };
+}
+
+fn uses_macro() {
+    my_macro!();
+    let _ = my_macro!(1);
 }
@@ -0,0 +1,176 @@
+use code_context_engineering::{extract_file, Origin, SymbolKind};
+
+const DEMO: &str = include_str!("codes/demo.rs");
+
+#[test]
+fn macro_rules_is_indexed_with_its_arms() {
+    let extracted = extract_file(DEMO).expect("demo fixture parses");
+
+    let my_macro = extracted
+        .symbol_named("my_macro")
+        .expect("my_macro is extracted as a symbol");
+    let SymbolKind::Macro { arms } = &my_macro.kind else {
+        panic!("my_macro should be extracted as SymbolKind::Macro, got {:?}", my_macro.kind);
+    };
+    assert_eq!(arms.len(), 2, "my_macro has a no-arg arm and an $val:expr arm");
+    assert!(arms[0].fragment_specifiers.is_empty());
+    assert_eq!(arms[1].fragment_specifiers, vec!["expr".to_string()]);
+}
+
+#[test]
+fn macro_invocations_resolve_to_the_definition() {
+    let extracted = extract_file(DEMO).expect("demo fixture parses");
+
+    let my_macro = extracted.symbol_named("my_macro").expect("my_macro exists");
+    let invocations: Vec<_> = extracted
+        .references
+        .iter()
+        .filter(|r| r.name == "my_macro")
+        .collect();
+
+    assert_eq!(invocations.len(), 2, "both call sites in uses_macro() are recorded");
+    for reference in invocations {
+        assert_eq!(reference.resolved, Some(my_macro.id));
+    }
+}
+
+#[test]
+fn members_of_a_type_includes_inherent_and_trait_methods() {
+    let extracted = extract_file(DEMO).expect("demo fixture parses");
+
+    let members = extracted
+        .members_of("MyStruct")
+        .expect("MyStruct has members");
+    let mut names: Vec<_> = members.members.iter().map(|s| s.name.as_str()).collect();
+    names.sort_unstable();
+    assert_eq!(names, vec!["default", "field", "new", "trait_method"]);
+
+    let mut implements = members.implements.clone();
+    implements.sort_unstable();
+    assert_eq!(implements, vec!["Default", "MyTrait"]);
+}
+
+#[test]
+fn members_of_resolves_even_when_the_impl_precedes_the_type() {
+    let source = r#"
+        impl MyTrait for MyStruct {
+            fn m(&self) {}
+        }
+        trait MyTrait {
+            fn m(&self);
+        }
+        struct MyStruct;
+    "#;
+    let extracted = extract_file(source).expect("source parses");
+
+    let members = extracted
+        .members_of("MyStruct")
+        .expect("MyStruct has members even though its impl comes first");
+    assert_eq!(
+        members.members.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(),
+        vec!["m"]
+    );
+    assert_eq!(members.implements, vec!["MyTrait"]);
+}
+
+#[test]
+fn use_declarations_resolve_grouped_and_glob_imports() {
+    let extracted = extract_file(DEMO).expect("demo fixture parses");
+
+    assert_eq!(
+        extracted.imports.resolve("HashMap"),
+        Some("std::collections::HashMap")
+    );
+    assert_eq!(
+        extracted.imports.resolve("BTreeMap"),
+        Some("std::collections::BTreeMap")
+    );
+    assert_eq!(
+        extracted.imports.resolve("HashSet"),
+        Some("std::collections::HashSet")
+    );
+    assert_eq!(extracted.imports.globs, vec!["std::fmt".to_string()]);
+}
+
+#[test]
+fn references_to_an_imported_type_are_annotated_with_their_origin() {
+    let extracted = extract_file(DEMO).expect("demo fixture parses");
+
+    let hash_map_refs: Vec<_> = extracted
+        .references
+        .iter()
+        .filter(|r| r.name == "HashMap")
+        .collect();
+
+    assert!(
+        !hash_map_refs.is_empty(),
+        "build_map()'s return type and body both name HashMap"
+    );
+    for reference in hash_map_refs {
+        assert_eq!(
+            reference.origin,
+            Some(Origin::External("std::collections::HashMap".to_string()))
+        );
+    }
+}
+
+#[test]
+fn references_to_a_same_file_symbol_are_annotated_as_local() {
+    let source = r#"
+        struct MyStruct;
+
+        fn build() -> MyStruct {
+            MyStruct
+        }
+    "#;
+    let extracted = extract_file(source).expect("source parses");
+
+    let my_struct = extracted.symbol_named("MyStruct").expect("MyStruct is extracted");
+    let struct_refs: Vec<_> = extracted
+        .references
+        .iter()
+        .filter(|r| r.name == "MyStruct")
+        .collect();
+
+    assert!(
+        !struct_refs.is_empty(),
+        "build()'s return type and body both name MyStruct"
+    );
+    for reference in struct_refs {
+        assert_eq!(reference.resolved, Some(my_struct.id));
+        assert_eq!(reference.origin, Some(Origin::Local(my_struct.id)));
+    }
+}
+
+#[test]
+fn a_local_binding_shadowing_a_symbol_name_is_not_resolved_to_it() {
+    let source = r#"
+        use std::collections::HashMap;
+
+        fn helper() -> i32 {
+            1
+        }
+
+        fn caller() {
+            let helper = 5;
+            let _x = helper + 1;
+            let map: HashMap<i32, i32> = HashMap::new();
+        }
+    "#;
+    let extracted = extract_file(source).expect("source parses");
+
+    extracted.symbol_named("helper").expect("helper fn exists");
+    let helper_refs: Vec<_> = extracted
+        .references
+        .iter()
+        .filter(|r| r.name == "helper")
+        .collect();
+    assert!(
+        helper_refs.is_empty(),
+        "the local `let helper = 5;` shadows the function, so `helper + 1` isn't a reference to it: {helper_refs:?}"
+    );
+
+    // Unrelated imported names in the same function are unaffected.
+    let hash_map_refs: Vec<_> = extracted.references.iter().filter(|r| r.name == "HashMap").collect();
+    assert!(!hash_map_refs.is_empty());
+}